@@ -14,6 +14,13 @@ export function readonlyMountArgs(hostPath: string, containerPath: string): stri
   return ['--mount', `type=bind,source=${hostPath},target=${containerPath},readonly`];
 }
 
+/** Returns CLI args for a read-only root filesystem with tmpfs scratch space. */
+export function readonlyRootfsArgs(): string[] {
+  // Apple Container's --tmpfs takes a bare path, without Docker-style ":exec"
+  // options. SKILL.md has a check that scripts in /tmp still run.
+  return ['--read-only', '--tmpfs', '/tmp', '--tmpfs', '/home/node'];
+}
+
 /** Returns the shell command to stop a container by name. */
 export function stopContainer(name: string): string {
   return `${CONTAINER_RUNTIME_BIN} stop ${name}`;
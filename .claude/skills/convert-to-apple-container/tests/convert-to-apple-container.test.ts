@@ -60,10 +60,12 @@ describe('convert-to-apple-container skill package', () => {
     expect(content).toContain('system start');
     expect(content).toContain('ls --format json');
     expect(content).toContain('type=bind,source=');
+    expect(content).toContain('readonlyRootfsArgs');
 
     // Should NOT contain Docker patterns
     expect(content).not.toContain('docker info');
     expect(content).not.toContain("'-v'");
     expect(content).not.toContain('--filter name=');
+    expect(content).not.toContain(':exec');
   });
 });
@@ -9,7 +9,8 @@ import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js'
 import { z } from 'zod';
 import fs from 'fs';
 import path from 'path';
-import { CronExpressionParser } from 'cron-parser';
+
+import { describeSchedule, scheduleError } from './schedule.js';
 
 const IPC_DIR = '/workspace/ipc';
 const MESSAGES_DIR = path.join(IPC_DIR, 'messages');
@@ -93,32 +94,13 @@ SCHEDULE VALUE FORMAT (all times are LOCAL timezone):
     target_group_jid: z.string().optional().describe('(Main group only) JID of the group to schedule the task for. Defaults to the current group.'),
   },
   async (args) => {
-    // Validate schedule_value before writing IPC
-    if (args.schedule_type === 'cron') {
-      try {
-        CronExpressionParser.parse(args.schedule_value);
-      } catch {
-        return {
-          content: [{ type: 'text' as const, text: `Invalid cron: "${args.schedule_value}". Use format like "0 9 * * *" (daily 9am) or "*/5 * * * *" (every 5 min).` }],
-          isError: true,
-        };
-      }
-    } else if (args.schedule_type === 'interval') {
-      const ms = parseInt(args.schedule_value, 10);
-      if (isNaN(ms) || ms <= 0) {
-        return {
-          content: [{ type: 'text' as const, text: `Invalid interval: "${args.schedule_value}". Must be positive milliseconds (e.g., "300000" for 5 min).` }],
-          isError: true,
-        };
-      }
-    } else if (args.schedule_type === 'once') {
-      const date = new Date(args.schedule_value);
-      if (isNaN(date.getTime())) {
-        return {
-          content: [{ type: 'text' as const, text: `Invalid timestamp: "${args.schedule_value}". Use ISO 8601 format like "2026-02-01T15:30:00.000Z".` }],
-          isError: true,
-        };
-      }
+    // Validate schedule_value before writing IPC, with the same rules the host applies
+    const error = scheduleError(args.schedule_type, args.schedule_value);
+    if (error) {
+      return {
+        content: [{ type: 'text' as const, text: error }],
+        isError: true,
+      };
     }
 
     // Non-main groups can only schedule for themselves
@@ -138,7 +120,7 @@ SCHEDULE VALUE FORMAT (all times are LOCAL timezone):
     const filename = writeIpcFile(TASKS_DIR, data);
 
     return {
-      content: [{ type: 'text' as const, text: `Task scheduled (${filename}): ${args.schedule_type} - ${args.schedule_value}` }],
+      content: [{ type: 'text' as const, text: `Task scheduled (${filename}): runs ${describeSchedule(args.schedule_type, args.schedule_value)}` }],
     };
   },
 );
@@ -219,6 +201,25 @@ server.tool(
   },
 );
 
+server.tool(
+  'run_task',
+  'Run a scheduled task right now, without changing its schedule. Use when the user asks to run a task early (e.g., "run my morning summary now").',
+  { task_id: z.string().describe('The task ID to run') },
+  async (args) => {
+    const data = {
+      type: 'run_task',
+      taskId: args.task_id,
+      groupFolder,
+      isMain,
+      timestamp: new Date().toISOString(),
+    };
+
+    writeIpcFile(TASKS_DIR, data);
+
+    return { content: [{ type: 'text' as const, text: `Task ${args.task_id} run requested.` }] };
+  },
+);
+
 server.tool(
   'cancel_task',
   'Cancel and delete a scheduled task.',
@@ -248,6 +249,7 @@ Use available_groups.json to find the JID for a group. The folder name should be
     name: z.string().describe('Display name for the group'),
     folder: z.string().describe('Folder name for group files (lowercase, hyphens, e.g., "family-chat")'),
     trigger: z.string().describe('Trigger word (e.g., "@Andy")'),
+    quiet_hours: z.string().optional().describe('Local time window when scheduled tasks are held, e.g. "23:00-07:00". Use "off" to ignore the global quiet hours.'),
   },
   async (args) => {
     if (!isMain) {
@@ -263,6 +265,7 @@ Use available_groups.json to find the JID for a group. The folder name should be
       name: args.name,
       folder: args.folder,
       trigger: args.trigger,
+      quietHours: args.quiet_hours,
       timestamp: new Date().toISOString(),
     };
 
@@ -274,6 +277,56 @@ Use available_groups.json to find the JID for a group. The folder name should be
   },
 );
 
+server.tool(
+  'pause_dispatch',
+  `Pause agent runs, for all groups or one group. Main group only. Messages and due tasks keep queuing but nothing runs until resume_dispatch is called. Running containers are asked to wind down. The main group itself is never paused. Use during incidents, e.g. runaway tasks or a group flooding the agent.`,
+  {
+    target_group_jid: z.string().optional().describe('JID of the group to pause. Omit to pause every group except main.'),
+  },
+  async (args) => {
+    if (!isMain) {
+      return {
+        content: [{ type: 'text' as const, text: 'Only the main group can pause dispatching.' }],
+        isError: true,
+      };
+    }
+
+    writeIpcFile(TASKS_DIR, {
+      type: 'pause_dispatch',
+      jid: args.target_group_jid,
+      timestamp: new Date().toISOString(),
+    });
+
+    const scope = args.target_group_jid ? `group ${args.target_group_jid}` : 'all groups except main';
+    return { content: [{ type: 'text' as const, text: `Dispatch pause requested for ${scope}.` }] };
+  },
+);
+
+server.tool(
+  'resume_dispatch',
+  'Resume agent runs paused with pause_dispatch. Main group only. Queued messages and tasks start running again.',
+  {
+    target_group_jid: z.string().optional().describe('JID of the group to resume. Omit to lift every pause.'),
+  },
+  async (args) => {
+    if (!isMain) {
+      return {
+        content: [{ type: 'text' as const, text: 'Only the main group can resume dispatching.' }],
+        isError: true,
+      };
+    }
+
+    writeIpcFile(TASKS_DIR, {
+      type: 'resume_dispatch',
+      jid: args.target_group_jid,
+      timestamp: new Date().toISOString(),
+    });
+
+    const scope = args.target_group_jid ? `group ${args.target_group_jid}` : 'all groups';
+    return { content: [{ type: 'text' as const, text: `Dispatch resume requested for ${scope}.` }] };
+  },
+);
+
 // Start the stdio transport
 const transport = new StdioServerTransport();
 await server.connect(transport);
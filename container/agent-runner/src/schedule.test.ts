@@ -0,0 +1,98 @@
+import { describe, it, expect } from 'vitest';
+
+import { describeSchedule, scheduleError } from './schedule.js';
+
+// --- scheduleError ---
+
+describe('scheduleError', () => {
+  it('accepts valid schedules', () => {
+    expect(scheduleError('cron', '0 9 * * *')).toBeNull();
+    expect(scheduleError('interval', '300000')).toBeNull();
+    expect(scheduleError('once', '2026-02-01T15:30:00')).toBeNull();
+  });
+
+  it('explains why a cron expression is invalid', () => {
+    expect(scheduleError('cron', 'not a cron')).toContain(
+      'Invalid cron expression "not a cron"',
+    );
+  });
+
+  it('rejects zero, negative, fractional, and non-numeric intervals', () => {
+    for (const value of ['0', '-5', '1.5', 'abc', '300000abc', '3.6e6', '0x10']) {
+      expect(scheduleError('interval', value)).toContain(
+        `Invalid interval "${value}"`,
+      );
+    }
+  });
+
+  it('rejects an invalid once timestamp', () => {
+    expect(scheduleError('once', 'tomorrow-ish')).toContain(
+      'Invalid timestamp "tomorrow-ish"',
+    );
+  });
+
+  it('rejects unknown schedule types', () => {
+    expect(scheduleError('weekly', 'monday')).toContain(
+      'Unknown schedule type "weekly"',
+    );
+  });
+});
+
+// --- describeSchedule ---
+
+describe('describeSchedule', () => {
+  it('describes daily cron at a fixed time', () => {
+    expect(describeSchedule('cron', '0 9 * * *')).toBe('every day at 09:00');
+  });
+
+  it('describes weekday cron', () => {
+    expect(describeSchedule('cron', '30 8 * * 1-5')).toBe(
+      'every weekday at 08:30',
+    );
+  });
+
+  it('describes specific weekdays', () => {
+    expect(describeSchedule('cron', '0 18 * * 1,3')).toBe(
+      'every Monday, Wednesday at 18:00',
+    );
+    expect(describeSchedule('cron', '0 10 * * 7')).toBe(
+      'every Sunday at 10:00',
+    );
+  });
+
+  it('describes monthly cron', () => {
+    expect(describeSchedule('cron', '0 9 1 * *')).toBe(
+      'on day 1 of every month at 09:00',
+    );
+  });
+
+  it('describes minute steps and hourly cron', () => {
+    expect(describeSchedule('cron', '*/5 * * * *')).toBe('every 5 minutes');
+    expect(describeSchedule('cron', '15 * * * *')).toBe(
+      'every hour at minute 15',
+    );
+  });
+
+  it('falls back to the raw expression for steps that do not divide an hour', () => {
+    // */7 fires at :56 and again at :00, so it is not "every 7 minutes"
+    expect(describeSchedule('cron', '*/7 * * * *')).toBe('cron "*/7 * * * *"');
+  });
+
+  it('falls back to the raw expression for complex cron', () => {
+    expect(describeSchedule('cron', '0 7-22 * * *')).toBe(
+      'cron "0 7-22 * * *"',
+    );
+  });
+
+  it('describes intervals in the largest whole unit', () => {
+    expect(describeSchedule('interval', '3600000')).toBe('every hour');
+    expect(describeSchedule('interval', '300000')).toBe('every 5 minutes');
+    expect(describeSchedule('interval', '90000')).toBe('every 90 seconds');
+  });
+
+  it('describes once schedules', () => {
+    expect(describeSchedule('once', '2026-02-01T15:30:00')).toBe(
+      'once at 2026-02-01T15:30:00',
+    );
+  });
+});
@@ -0,0 +1,126 @@
+/**
+ * Schedule checks and descriptions for the schedule_task MCP tool.
+ * The host re-validates every task (validateSchedule in src/task-scheduler.ts);
+ * this pre-check only exists so the agent can report errors to the user.
+ */
+
+import { CronExpressionParser } from 'cron-parser';
+
+/**
+ * Check a schedule value, returning an error message or null if valid.
+ * Errors are phrased so they can be shown to the user as-is.
+ * Cron validity doesn't depend on timezone, so none is passed.
+ */
+export function scheduleError(
+  scheduleType: string,
+  scheduleValue: string,
+): string | null {
+  if (scheduleType === 'cron') {
+    try {
+      CronExpressionParser.parse(scheduleValue);
+      return null;
+    } catch (err) {
+      const reason = err instanceof Error ? err.message : String(err);
+      return `Invalid cron expression "${scheduleValue}": ${reason}`;
+    }
+  }
+  if (scheduleType === 'interval') {
+    const ms = /^\d+$/.test(scheduleValue) ? parseInt(scheduleValue, 10) : 0;
+    if (ms <= 0) {
+      return `Invalid interval "${scheduleValue}": must be a positive whole number of milliseconds`;
+    }
+    return null;
+  }
+  if (scheduleType === 'once') {
+    if (isNaN(new Date(scheduleValue).getTime())) {
+      return `Invalid timestamp "${scheduleValue}": use ISO 8601 like "2026-02-01T15:30:00"`;
+    }
+    return null;
+  }
+  return `Unknown schedule type "${scheduleType}": use cron, interval, or once`;
+}
+
+const WEEKDAYS = [
+  'Sunday',
+  'Monday',
+  'Tuesday',
+  'Wednesday',
+  'Thursday',
+  'Friday',
+  'Saturday',
+];
+
+function describeDuration(ms: number): string {
+  const units: Array<[number, string]> = [
+    [86400000, 'day'],
+    [3600000, 'hour'],
+    [60000, 'minute'],
+    [1000, 'second'],
+  ];
+  for (const [size, name] of units) {
+    if (ms % size === 0) {
+      const n = ms / size;
+      return n === 1 ? name : `${n} ${name}s`;
+    }
+  }
+  return `${ms} milliseconds`;
+}
+
+function describeDays(dayOfMonth: string, month: string, dayOfWeek: string): string | null {
+  if (month !== '*') return null;
+  if (dayOfMonth === '*') {
+    if (dayOfWeek === '*') return 'every day';
+    if (dayOfWeek === '1-5') return 'every weekday';
+    if (dayOfWeek === '0,6' || dayOfWeek === '6,0') return 'every weekend day';
+    const days = dayOfWeek.split(',');
+    if (days.every((d) => /^[0-7]$/.test(d))) {
+      return `every ${days.map((d) => WEEKDAYS[Number(d) % 7]).join(', ')}`;
+    }
+    return null;
+  }
+  if (dayOfWeek === '*' && /^\d+$/.test(dayOfMonth)) {
+    return `on day ${dayOfMonth} of every month`;
+  }
+  return null;
+}
+
+/**
+ * Human-readable summary of a schedule, e.g. "every weekday at 09:00".
+ * Cron expressions outside the common shapes fall back to the raw expression.
+ */
+export function describeSchedule(
+  scheduleType: string,
+  scheduleValue: string,
+): string {
+  if (scheduleType === 'interval') {
+    return `every ${describeDuration(Number(scheduleValue))}`;
+  }
+  if (scheduleType === 'once') {
+    return `once at ${scheduleValue}`;
+  }
+
+  const fields = scheduleValue.trim().split(/\s+/);
+  if (fields.length === 5) {
+    const [minute, hour, dayOfMonth, month, dayOfWeek] = fields;
+    const days = describeDays(dayOfMonth, month, dayOfWeek);
+
+    // Cron steps restart every hour, so only divisors of 60 are regular
+    const step = minute.match(/^\*\/(\d+)$/);
+    if (
+      step &&
+      60 % Number(step[1]) === 0 &&
+      hour === '*' &&
+      days === 'every day'
+    ) {
+      return `every ${describeDuration(Number(step[1]) * 60000)}`;
+    }
+    if (/^\d+$/.test(minute) && hour === '*' && days === 'every day') {
+      return `every hour at minute ${minute}`;
+    }
+    if (/^\d+$/.test(minute) && /^\d+$/.test(hour) && days) {
+      const time = `${hour.padStart(2, '0')}:${minute.padStart(2, '0')}`;
+      return `${days} at ${time}`;
+    }
+  }
+  return `cron "${scheduleValue}"`;
+}
@@ -588,6 +588,39 @@ describe('WhatsAppChannel', () => {
       );
     });
 
+    it('translates LID group participants to phone JIDs', async () => {
+      const opts = createTestOpts();
+      const channel = new WhatsAppChannel(opts);
+
+      await connectChannel(channel);
+      Object.assign(fakeSocket, {
+        signalRepository: {
+          lidMapping: {
+            getPNForLID: vi.fn().mockResolvedValue('5551234:0@s.whatsapp.net'),
+          },
+        },
+      });
+
+      await triggerMessages([
+        {
+          key: {
+            id: 'msg-lid-participant',
+            remoteJid: 'registered@g.us',
+            participant: '1112223334@lid',
+            fromMe: false,
+          },
+          message: { conversation: 'From a LID participant' },
+          pushName: 'Alice',
+          messageTimestamp: Math.floor(Date.now() / 1000),
+        },
+      ]);
+
+      expect(opts.onMessage).toHaveBeenCalledWith(
+        'registered@g.us',
+        expect.objectContaining({ sender: '5551234@s.whatsapp.net' }),
+      );
+    });
+
     it('passes through non-LID JIDs unchanged', async () => {
       const opts = createTestOpts();
       const channel = new WhatsAppChannel(opts);
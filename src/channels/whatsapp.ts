@@ -174,7 +174,11 @@ export class WhatsAppChannel implements Channel {
           // Skip protocol messages with no text content (encryption keys, read receipts, etc.)
           if (!content) continue;
 
-          const sender = msg.key.participant || msg.key.remoteJid || '';
+          // Group participants arrive as LIDs in Baileys v7; store the phone
+          // JID so sender allowlists and names stay stable.
+          const sender = await this.translateJid(
+            msg.key.participant || msg.key.remoteJid || '',
+          );
           const senderName = msg.pushName || sender.split('@')[0];
 
           const fromMe = msg.key.fromMe || false;
@@ -29,6 +29,13 @@ export const MOUNT_ALLOWLIST_PATH = path.join(
   'nanoclaw',
   'mount-allowlist.json',
 );
+// Sender allowlist: who may trigger the agent per chat, also kept outside project root
+export const SENDER_ALLOWLIST_PATH = path.join(
+  HOME_DIR,
+  '.config',
+  'nanoclaw',
+  'sender-allowlist.json',
+);
 export const STORE_DIR = path.resolve(PROJECT_ROOT, 'store');
 export const GROUPS_DIR = path.resolve(PROJECT_ROOT, 'groups');
 export const DATA_DIR = path.resolve(PROJECT_ROOT, 'data');
@@ -44,6 +51,15 @@ export const CONTAINER_MAX_OUTPUT_SIZE = parseInt(
   process.env.CONTAINER_MAX_OUTPUT_SIZE || '10485760',
   10,
 ); // 10MB default
+export const CONTAINER_LOG_RETENTION = parseInt(
+  process.env.CONTAINER_LOG_RETENTION || '0',
+  10,
+); // Run logs kept per group, oldest deleted first (0 = keep all)
+export const CONTAINER_LOG_MAX_BYTES = parseInt(
+  process.env.CONTAINER_LOG_MAX_BYTES || '0',
+  10,
+); // Total run log bytes kept per group, oldest deleted first (0 = no limit)
+export const CONTAINER_LOG_OUTPUT = process.env.CONTAINER_LOG_OUTPUT === 'true'; // Write stdout/stderr into every run log, not just errors
 export const IPC_POLL_INTERVAL = 1000;
 export const IDLE_TIMEOUT = parseInt(
   process.env.IDLE_TIMEOUT || '1800000',
@@ -67,3 +83,7 @@ export const TRIGGER_PATTERN = new RegExp(
 // Uses system timezone by default
 export const TIMEZONE =
   process.env.TZ || Intl.DateTimeFormat().resolvedOptions().timeZone;
+
+// Quiet hours for scheduled tasks, e.g. "23:00-07:00" in TIMEZONE.
+// Due tasks are held until the window ends. Groups can override this.
+export const QUIET_HOURS = process.env.QUIET_HOURS || '';
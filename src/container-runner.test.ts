@@ -6,9 +6,17 @@ import { PassThrough } from 'stream';
 const OUTPUT_START_MARKER = '---NANOCLAW_OUTPUT_START---';
 const OUTPUT_END_MARKER = '---NANOCLAW_OUTPUT_END---';
 
+// Mutable so tests can turn output logging on
+const logConfig = vi.hoisted(() => ({ output: false }));
+
 // Mock config
 vi.mock('./config.js', () => ({
   CONTAINER_IMAGE: 'nanoclaw-agent:latest',
+  CONTAINER_LOG_MAX_BYTES: 0,
+  get CONTAINER_LOG_OUTPUT() {
+    return logConfig.output;
+  },
+  CONTAINER_LOG_RETENTION: 0,
   CONTAINER_MAX_OUTPUT_SIZE: 10485760,
   CONTAINER_TIMEOUT: 1800000, // 30min
   DATA_DIR: '/tmp/nanoclaw-test-data',
@@ -40,6 +48,7 @@ vi.mock('fs', async () => {
       readdirSync: vi.fn(() => []),
       statSync: vi.fn(() => ({ isDirectory: () => false })),
       copyFileSync: vi.fn(),
+      unlinkSync: vi.fn(),
     },
   };
 });
@@ -81,7 +90,14 @@ vi.mock('child_process', async () => {
   };
 });
 
-import { runContainerAgent, ContainerOutput } from './container-runner.js';
+import { spawn } from 'child_process';
+import fs from 'fs';
+
+import {
+  pruneContainerLogs,
+  runContainerAgent,
+  ContainerOutput,
+} from './container-runner.js';
 import type { RegisteredGroup } from './types.js';
 
 const testGroup: RegisteredGroup = {
@@ -200,3 +216,155 @@ describe('container-runner timeout behavior', () => {
     expect(result.newSessionId).toBe('session-456');
   });
 });
+
+describe('container-runner read-only rootfs', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    fakeProc = createFakeProcess();
+    vi.mocked(spawn).mockClear();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  async function runAndGetArgs(group: RegisteredGroup): Promise<string[]> {
+    const resultPromise = runContainerAgent(group, testInput, () => {});
+    fakeProc.emit('close', 0);
+    await vi.advanceTimersByTimeAsync(10);
+    await resultPromise;
+    return vi.mocked(spawn).mock.calls[0][1] as string[];
+  }
+
+  it('keeps the root filesystem writable by default', async () => {
+    const args = await runAndGetArgs(testGroup);
+    expect(args).not.toContain('--read-only');
+  });
+
+  it('adds --read-only and tmpfs mounts when configured', async () => {
+    const args = await runAndGetArgs({
+      ...testGroup,
+      containerConfig: { readOnlyRootfs: true },
+    });
+    expect(args).toContain('--read-only');
+    expect(args).toEqual(
+      expect.arrayContaining(['--tmpfs', '/tmp:exec', '--tmpfs', '/home/node:exec']),
+    );
+  });
+});
+
+describe('container-runner run logs', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    fakeProc = createFakeProcess();
+    vi.mocked(fs.writeFileSync).mockClear();
+  });
+
+  afterEach(() => {
+    logConfig.output = false;
+    vi.useRealTimers();
+  });
+
+  async function runAndGetLog(): Promise<string> {
+    const resultPromise = runContainerAgent(testGroup, testInput, () => {});
+    fakeProc.stdout.push('agent stdout line\n');
+    fakeProc.stderr.push('agent stderr line\n');
+    await vi.advanceTimersByTimeAsync(10);
+    fakeProc.emit('close', 0);
+    await vi.advanceTimersByTimeAsync(10);
+    await resultPromise;
+
+    const logWrite = vi
+      .mocked(fs.writeFileSync)
+      .mock.calls.find(
+        (call) => typeof call[0] === 'string' && /container-.*\.log$/.test(call[0]),
+      );
+    return String(logWrite![1]);
+  }
+
+  it('leaves output out of successful run logs by default', async () => {
+    const log = await runAndGetLog();
+    expect(log).not.toContain('agent stdout line');
+    expect(log).not.toContain('agent stderr line');
+  });
+
+  it('writes stdout and stderr to every run log when enabled', async () => {
+    logConfig.output = true;
+    const log = await runAndGetLog();
+    expect(log).toContain('=== Stdout ===');
+    expect(log).toContain('agent stdout line');
+    expect(log).toContain('=== Stderr ===');
+    expect(log).toContain('agent stderr line');
+  });
+});
+
+describe('pruneContainerLogs', () => {
+  beforeEach(() => {
+    vi.mocked(fs.readdirSync).mockClear();
+    vi.mocked(fs.unlinkSync).mockClear();
+  });
+
+  afterEach(() => {
+    // Restore the default stat mock used by mount building
+    vi.mocked(fs.statSync).mockImplementation(
+      () => ({ isDirectory: () => false }) as unknown as fs.Stats,
+    );
+  });
+
+  it('deletes the oldest logs beyond the retention limit', () => {
+    vi.mocked(fs.readdirSync).mockReturnValueOnce([
+      'container-2026-01-03T00-00-00-000Z.log',
+      'container-2026-01-01T00-00-00-000Z.log',
+      'notes.txt',
+      'container-2026-01-02T00-00-00-000Z.log',
+    ] as unknown as ReturnType<typeof fs.readdirSync>);
+
+    pruneContainerLogs('/tmp/logs', 2);
+
+    expect(fs.unlinkSync).toHaveBeenCalledTimes(1);
+    expect(fs.unlinkSync).toHaveBeenCalledWith(
+      '/tmp/logs/container-2026-01-01T00-00-00-000Z.log',
+    );
+  });
+
+  it('keeps everything when retention and byte budget are 0', () => {
+    pruneContainerLogs('/tmp/logs', 0, 0);
+    expect(fs.readdirSync).not.toHaveBeenCalledWith('/tmp/logs');
+    expect(fs.unlinkSync).not.toHaveBeenCalled();
+  });
+
+  it('deletes the oldest logs beyond the byte budget', () => {
+    vi.mocked(fs.readdirSync).mockReturnValueOnce([
+      'container-2026-01-01T00-00-00-000Z.log',
+      'container-2026-01-02T00-00-00-000Z.log',
+      'container-2026-01-03T00-00-00-000Z.log',
+    ] as unknown as ReturnType<typeof fs.readdirSync>);
+    vi.mocked(fs.statSync).mockImplementation(
+      () => ({ size: 400 }) as unknown as fs.Stats,
+    );
+
+    pruneContainerLogs('/tmp/logs', 0, 1000);
+
+    expect(fs.unlinkSync).toHaveBeenCalledTimes(1);
+    expect(fs.unlinkSync).toHaveBeenCalledWith(
+      '/tmp/logs/container-2026-01-01T00-00-00-000Z.log',
+    );
+  });
+
+  it('always keeps the newest log even if it exceeds the byte budget', () => {
+    vi.mocked(fs.readdirSync).mockReturnValueOnce([
+      'container-2026-01-01T00-00-00-000Z.log',
+      'container-2026-01-02T00-00-00-000Z.log',
+    ] as unknown as ReturnType<typeof fs.readdirSync>);
+    vi.mocked(fs.statSync).mockImplementation(
+      () => ({ size: 5000 }) as unknown as fs.Stats,
+    );
+
+    pruneContainerLogs('/tmp/logs', 0, 1000);
+
+    expect(fs.unlinkSync).toHaveBeenCalledTimes(1);
+    expect(fs.unlinkSync).toHaveBeenCalledWith(
+      '/tmp/logs/container-2026-01-01T00-00-00-000Z.log',
+    );
+  });
+});
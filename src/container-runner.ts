@@ -9,6 +9,9 @@ import path from 'path';
 
 import {
   CONTAINER_IMAGE,
+  CONTAINER_LOG_MAX_BYTES,
+  CONTAINER_LOG_OUTPUT,
+  CONTAINER_LOG_RETENTION,
   CONTAINER_MAX_OUTPUT_SIZE,
   CONTAINER_TIMEOUT,
   DATA_DIR,
@@ -17,7 +20,12 @@ import {
 } from './config.js';
 import { readEnvFile } from './env.js';
 import { logger } from './logger.js';
-import { CONTAINER_RUNTIME_BIN, readonlyMountArgs, stopContainer } from './container-runtime.js';
+import {
+  CONTAINER_RUNTIME_BIN,
+  readonlyMountArgs,
+  readonlyRootfsArgs,
+  stopContainer,
+} from './container-runtime.js';
 import { validateAdditionalMounts } from './mount-security.js';
 import { RegisteredGroup } from './types.js';
 
@@ -177,6 +185,39 @@ function buildVolumeMounts(
   return mounts;
 }
 
+/**
+ * Delete the oldest container run logs so at most `keep` remain and their
+ * total size stays within `maxBytes`. A limit of 0 disables that check.
+ * The newest log is always kept, even if it alone exceeds the byte budget.
+ * Log filenames embed an ISO timestamp, so name order is run order.
+ */
+export function pruneContainerLogs(
+  logsDir: string,
+  keep: number,
+  maxBytes = 0,
+): void {
+  if (keep <= 0 && maxBytes <= 0) return;
+  try {
+    const logs = fs
+      .readdirSync(logsDir)
+      .filter((f) => f.startsWith('container-') && f.endsWith('.log'))
+      .sort()
+      .reverse();
+    let totalBytes = 0;
+    logs.forEach((file, index) => {
+      const filePath = path.join(logsDir, file);
+      totalBytes += maxBytes > 0 ? fs.statSync(filePath).size : 0;
+      const overCount = keep > 0 && index >= keep;
+      const overBytes = maxBytes > 0 && index > 0 && totalBytes > maxBytes;
+      if (overCount || overBytes) {
+        fs.unlinkSync(filePath);
+      }
+    });
+  } catch (err) {
+    logger.warn({ logsDir, err }, 'Failed to prune container logs');
+  }
+}
+
 /**
  * Read allowed secrets from .env for passing to the container via stdin.
  * Secrets are never written to disk or mounted as files.
@@ -185,9 +226,18 @@ function readSecrets(): Record<string, string> {
   return readEnvFile(['CLAUDE_CODE_OAUTH_TOKEN', 'ANTHROPIC_API_KEY']);
 }
 
-function buildContainerArgs(mounts: VolumeMount[], containerName: string): string[] {
+function buildContainerArgs(
+  mounts: VolumeMount[],
+  containerName: string,
+  readOnlyRootfs = false,
+): string[] {
   const args: string[] = ['run', '-i', '--rm', '--name', containerName];
 
+  // Optional hardening: agents can only write to explicit mounts and scratch tmpfs
+  if (readOnlyRootfs) {
+    args.push(...readonlyRootfsArgs());
+  }
+
   // Run as host user so bind-mounted files are accessible.
   // Skip when running as root (uid 0), as the container's node user (uid 1000),
   // or when getuid is unavailable (native Windows without WSL).
@@ -225,7 +275,11 @@ export async function runContainerAgent(
   const mounts = buildVolumeMounts(group, input.isMain);
   const safeName = group.folder.replace(/[^a-zA-Z0-9-]/g, '-');
   const containerName = `nanoclaw-${safeName}-${Date.now()}`;
-  const containerArgs = buildContainerArgs(mounts, containerName);
+  const containerArgs = buildContainerArgs(
+    mounts,
+    containerName,
+    group.containerConfig?.readOnlyRootfs,
+  );
 
   logger.debug(
     {
@@ -392,7 +446,22 @@ export async function runContainerAgent(
           `Duration: ${duration}ms`,
           `Exit Code: ${code}`,
           `Had Streaming Output: ${hadStreamingOutput}`,
+          ...(CONTAINER_LOG_OUTPUT
+            ? [
+                ``,
+                `=== Stderr${stderrTruncated ? ' (TRUNCATED)' : ''} ===`,
+                stderr,
+                ``,
+                `=== Stdout${stdoutTruncated ? ' (TRUNCATED)' : ''} ===`,
+                stdout,
+              ]
+            : []),
         ].join('\n'));
+        pruneContainerLogs(
+          logsDir,
+          CONTAINER_LOG_RETENTION,
+          CONTAINER_LOG_MAX_BYTES,
+        );
 
         // Timeout after output = idle cleanup, not failure.
         // The agent already sent its response; this is just the
@@ -477,10 +546,24 @@ export async function runContainerAgent(
             .join('\n'),
           ``,
         );
+        if (CONTAINER_LOG_OUTPUT) {
+          logLines.push(
+            `=== Stderr${stderrTruncated ? ' (TRUNCATED)' : ''} ===`,
+            stderr,
+            ``,
+            `=== Stdout${stdoutTruncated ? ' (TRUNCATED)' : ''} ===`,
+            stdout,
+          );
+        }
       }
 
       fs.writeFileSync(logFile, logLines.join('\n'));
       logger.debug({ logFile, verbose: isVerbose }, 'Container log written');
+      pruneContainerLogs(
+        logsDir,
+        CONTAINER_LOG_RETENTION,
+        CONTAINER_LOG_MAX_BYTES,
+      );
 
       if (code !== 0) {
         logger.error(
@@ -19,6 +19,7 @@ vi.mock('child_process', () => ({
 import {
   CONTAINER_RUNTIME_BIN,
   readonlyMountArgs,
+  readonlyRootfsArgs,
   stopContainer,
   ensureContainerRuntimeRunning,
   cleanupOrphans,
@@ -38,6 +39,18 @@ describe('readonlyMountArgs', () => {
   });
 });
 
+describe('readonlyRootfsArgs', () => {
+  it('returns --read-only with executable tmpfs for /tmp and home', () => {
+    expect(readonlyRootfsArgs()).toEqual([
+      '--read-only',
+      '--tmpfs',
+      '/tmp:exec',
+      '--tmpfs',
+      '/home/node:exec',
+    ]);
+  });
+});
+
 describe('stopContainer', () => {
   it('returns stop command using CONTAINER_RUNTIME_BIN', () => {
     expect(stopContainer('nanoclaw-test-123')).toBe(
@@ -14,6 +14,19 @@ export function readonlyMountArgs(hostPath: string, containerPath: string): stri
   return ['-v', `${hostPath}:${containerPath}:ro`];
 }
 
+/** Returns CLI args for a read-only root filesystem with tmpfs scratch space. */
+export function readonlyRootfsArgs(): string[] {
+  // Agent tooling writes to /tmp (compiled runner) and $HOME (CLI state, caches).
+  // tmpfs defaults to noexec; agents run scripts and native modules from both.
+  return [
+    '--read-only',
+    '--tmpfs',
+    '/tmp:exec',
+    '--tmpfs',
+    '/home/node:exec',
+  ];
+}
+
 /** Returns the shell command to stop a container by name. */
 export function stopContainer(name: string): string {
   return `${CONTAINER_RUNTIME_BIN} stop ${name}`;
@@ -421,4 +421,122 @@ describe('GroupQueue', () => {
     resolveProcess!();
     await vi.advanceTimersByTimeAsync(10);
   });
+
+  // --- Dispatch pause ---
+
+  it('queues but does not run work while paused globally', async () => {
+    const processMessages = vi.fn(async () => true);
+    queue.setProcessMessagesFn(processMessages);
+    const taskFn = vi.fn(async () => {});
+
+    queue.pauseAll();
+    queue.enqueueMessageCheck('group1@g.us');
+    queue.enqueueTask('group2@g.us', 'task-1', taskFn);
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).not.toHaveBeenCalled();
+    expect(taskFn).not.toHaveBeenCalled();
+
+    queue.resumeAll();
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).toHaveBeenCalledWith('group1@g.us');
+    expect(taskFn).toHaveBeenCalledTimes(1);
+  });
+
+  it('keeps exempt groups running during a global pause', async () => {
+    const processMessages = vi.fn(async () => true);
+    queue.setProcessMessagesFn(processMessages);
+
+    queue.pauseAll(['main@g.us']);
+    queue.enqueueMessageCheck('main@g.us');
+    queue.enqueueMessageCheck('group1@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).toHaveBeenCalledTimes(1);
+    expect(processMessages).toHaveBeenCalledWith('main@g.us');
+  });
+
+  it('pauses and resumes a single group', async () => {
+    const processMessages = vi.fn(async () => true);
+    queue.setProcessMessagesFn(processMessages);
+
+    queue.pauseGroup('group1@g.us');
+    queue.enqueueMessageCheck('group1@g.us');
+    queue.enqueueMessageCheck('group2@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).toHaveBeenCalledTimes(1);
+    expect(processMessages).toHaveBeenCalledWith('group2@g.us');
+
+    queue.resumeGroup('group1@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).toHaveBeenCalledWith('group1@g.us');
+  });
+
+  it('keeps a group paused on resumeGroup while a global pause is active', async () => {
+    const processMessages = vi.fn(async () => true);
+    queue.setProcessMessagesFn(processMessages);
+
+    queue.pauseAll();
+    queue.pauseGroup('group1@g.us');
+    queue.enqueueMessageCheck('group1@g.us');
+    queue.resumeGroup('group1@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).not.toHaveBeenCalled();
+    expect(queue.isPaused('group1@g.us')).toBe(true);
+  });
+
+  it('does not pipe messages into an active container while paused', async () => {
+    const fs = await import('fs');
+    let resolveProcess: () => void;
+    const processMessages = vi.fn(async () => {
+      await new Promise<void>((resolve) => {
+        resolveProcess = resolve;
+      });
+      return true;
+    });
+    queue.setProcessMessagesFn(processMessages);
+
+    queue.enqueueMessageCheck('group1@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+    queue.registerProcess('group1@g.us', {} as any, 'container-1', 'test-group');
+
+    const writeFileSync = vi.mocked(fs.default.writeFileSync);
+    writeFileSync.mockClear();
+
+    queue.pauseGroup('group1@g.us');
+
+    // Pausing asks the running container to wind down
+    const closeWrites = writeFileSync.mock.calls.filter(
+      (call) => typeof call[0] === 'string' && call[0].endsWith('_close'),
+    );
+    expect(closeWrites).toHaveLength(1);
+    expect(queue.sendMessage('group1@g.us', 'hello')).toBe(false);
+
+    resolveProcess!();
+    await vi.advanceTimersByTimeAsync(10);
+  });
+
+  it('restores a saved pause state before work is enqueued', async () => {
+    const processMessages = vi.fn(async () => true);
+
+    const before = new GroupQueue();
+    before.pauseAll(['main@g.us']);
+    before.pauseGroup('group2@g.us');
+    const saved = JSON.parse(JSON.stringify(before.getPauseState()));
+
+    // Simulate a restart
+    queue.restorePauseState(saved);
+    queue.setProcessMessagesFn(processMessages);
+    queue.enqueueMessageCheck('group1@g.us');
+    queue.enqueueMessageCheck('main@g.us');
+    await vi.advanceTimersByTimeAsync(10);
+
+    expect(processMessages).toHaveBeenCalledTimes(1);
+    expect(processMessages).toHaveBeenCalledWith('main@g.us');
+    expect(queue.isPaused('group2@g.us')).toBe(true);
+  });
 });
@@ -11,6 +11,12 @@ interface QueuedTask {
   fn: () => Promise<void>;
 }
 
+export interface DispatchPauseState {
+  all: boolean;
+  exempt: string[];
+  groups: string[];
+}
+
 const MAX_RETRIES = 5;
 const BASE_RETRY_MS = 5000;
 
@@ -33,6 +39,10 @@ export class GroupQueue {
   private processMessagesFn: ((groupJid: string) => Promise<boolean>) | null =
     null;
   private shuttingDown = false;
+  // Dispatch pause: work keeps queuing but nothing new starts until resumed
+  private pausedAll = false;
+  private pauseExempt = new Set<string>();
+  private pausedGroups = new Set<string>();
 
   private getGroup(groupJid: string): GroupState {
     let state = this.groups.get(groupJid);
@@ -62,6 +72,12 @@ export class GroupQueue {
 
     const state = this.getGroup(groupJid);
 
+    if (this.isPaused(groupJid)) {
+      state.pendingMessages = true;
+      logger.debug({ groupJid }, 'Dispatch paused, message queued');
+      return;
+    }
+
     if (state.active) {
       state.pendingMessages = true;
       logger.debug({ groupJid }, 'Container active, message queued');
@@ -96,6 +112,12 @@ export class GroupQueue {
       return;
     }
 
+    if (this.isPaused(groupJid)) {
+      state.pendingTasks.push({ id: taskId, groupJid, fn });
+      logger.debug({ groupJid, taskId }, 'Dispatch paused, task queued');
+      return;
+    }
+
     if (state.active) {
       state.pendingTasks.push({ id: taskId, groupJid, fn });
       if (state.idleWaiting) {
@@ -149,6 +171,7 @@ export class GroupQueue {
   sendMessage(groupJid: string, text: string): boolean {
     const state = this.getGroup(groupJid);
     if (!state.active || !state.groupFolder || state.isTaskContainer) return false;
+    if (this.isPaused(groupJid)) return false;
     state.idleWaiting = false; // Agent is about to receive work, no longer idle
 
     const inputDir = path.join(DATA_DIR, 'ipc', state.groupFolder, 'input');
@@ -274,6 +297,12 @@ export class GroupQueue {
 
     const state = this.getGroup(groupJid);
 
+    // Paused groups keep their pending work; free the slot for others
+    if (this.isPaused(groupJid)) {
+      this.drainWaiting();
+      return;
+    }
+
     // Tasks first (they won't be re-discovered from SQLite like messages)
     if (state.pendingTasks.length > 0) {
       const task = state.pendingTasks.shift()!;
@@ -303,6 +332,9 @@ export class GroupQueue {
       const nextJid = this.waitingGroups.shift()!;
       const state = this.getGroup(nextJid);
 
+      // Paused groups are re-queued by resume
+      if (this.isPaused(nextJid)) continue;
+
       // Prioritize tasks over messages
       if (state.pendingTasks.length > 0) {
         const task = state.pendingTasks.shift()!;
@@ -318,6 +350,99 @@ export class GroupQueue {
     }
   }
 
+  /**
+   * Pause dispatching for every group except those in exceptJids.
+   * Messages and tasks keep queuing; running containers are asked to wind down.
+   */
+  pauseAll(exceptJids: string[] = []): void {
+    this.pausedAll = true;
+    this.pauseExempt = new Set(exceptJids);
+    for (const groupJid of this.groups.keys()) {
+      if (this.isPaused(groupJid)) this.closeStdin(groupJid);
+    }
+    logger.warn({ exempt: exceptJids }, 'Dispatch paused for all groups');
+  }
+
+  /**
+   * Pause dispatching for a single group.
+   */
+  pauseGroup(groupJid: string): void {
+    this.pausedGroups.add(groupJid);
+    this.closeStdin(groupJid);
+    logger.warn({ groupJid }, 'Dispatch paused for group');
+  }
+
+  /**
+   * Lift the global pause and every per-group pause, then start queued work.
+   */
+  resumeAll(): void {
+    this.pausedAll = false;
+    this.pauseExempt.clear();
+    this.pausedGroups.clear();
+    logger.info('Dispatch resumed for all groups');
+    for (const groupJid of this.groups.keys()) {
+      this.startPending(groupJid);
+    }
+  }
+
+  /**
+   * Lift a per-group pause. A global pause still applies.
+   */
+  resumeGroup(groupJid: string): void {
+    this.pausedGroups.delete(groupJid);
+    logger.info({ groupJid }, 'Dispatch resumed for group');
+    this.startPending(groupJid);
+  }
+
+  /** Snapshot of the pause flags, for persisting across restarts. */
+  getPauseState(): DispatchPauseState {
+    return {
+      all: this.pausedAll,
+      exempt: [...this.pauseExempt],
+      groups: [...this.pausedGroups],
+    };
+  }
+
+  /**
+   * Restore pause flags saved by getPauseState. Call before any work is
+   * enqueued so a restart mid-incident doesn't dispatch the backlog.
+   */
+  restorePauseState(state: DispatchPauseState): void {
+    this.pausedAll = state.all;
+    this.pauseExempt = new Set(state.exempt);
+    this.pausedGroups = new Set(state.groups);
+    if (state.all || state.groups.length > 0) {
+      logger.warn(state, 'Dispatch pause restored');
+    }
+  }
+
+  isPaused(groupJid: string): boolean {
+    return (
+      this.pausedGroups.has(groupJid) ||
+      (this.pausedAll && !this.pauseExempt.has(groupJid))
+    );
+  }
+
+  /**
+   * Start work that queued up while a group was paused.
+   */
+  private startPending(groupJid: string): void {
+    if (this.shuttingDown || this.isPaused(groupJid)) return;
+
+    const state = this.getGroup(groupJid);
+    if (state.active) return;
+    if (state.pendingTasks.length === 0 && !state.pendingMessages) return;
+
+    if (this.activeCount >= MAX_CONCURRENT_CONTAINERS) {
+      if (!this.waitingGroups.includes(groupJid)) {
+        this.waitingGroups.push(groupJid);
+      }
+      return;
+    }
+
+    this.drainGroup(groupJid);
+  }
+
   async shutdown(_gracePeriodMs: number): Promise<void> {
     this.shuttingDown = true;
 
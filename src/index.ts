@@ -35,7 +35,12 @@ import {
 import { GroupQueue } from './group-queue.js';
 import { startIpcWatcher } from './ipc.js';
 import { findChannel, formatMessages, formatOutbound } from './router.js';
-import { startSchedulerLoop } from './task-scheduler.js';
+import { filterAllowedMessages } from './sender-allowlist.js';
+import {
+  SchedulerDependencies,
+  runTaskNow,
+  startSchedulerLoop,
+} from './task-scheduler.js';
 import { Channel, NewMessage, RegisteredGroup } from './types.js';
 import { logger } from './logger.js';
 
@@ -61,6 +66,14 @@ function loadState(): void {
     logger.warn('Corrupted last_agent_timestamp in DB, resetting');
     lastAgentTimestamp = {};
   }
+  const dispatchPause = getRouterState('dispatch_pause');
+  if (dispatchPause) {
+    try {
+      queue.restorePauseState(JSON.parse(dispatchPause));
+    } catch {
+      logger.warn('Corrupted dispatch_pause in DB, ignoring');
+    }
+  }
   sessions = getAllSessions();
   registeredGroups = getAllRegisteredGroups();
   logger.info(
@@ -75,6 +88,7 @@ function saveState(): void {
     'last_agent_timestamp',
     JSON.stringify(lastAgentTimestamp),
   );
+  setRouterState('dispatch_pause', JSON.stringify(queue.getPauseState()));
 }
 
 function registerGroup(jid: string, group: RegisteredGroup): void {
@@ -135,15 +149,21 @@ async function processGroupMessages(chatJid: string): Promise<boolean> {
 
   if (missedMessages.length === 0) return true;
 
-  // For non-main groups, check if trigger is required and present
-  if (!isMainGroup && group.requiresTrigger !== false) {
-    const hasTrigger = missedMessages.some((m) =>
-      TRIGGER_PATTERN.test(m.content.trim()),
-    );
-    if (!hasTrigger) return true;
-  }
+  // Messages from senders outside the allowlist never reach the agent.
+  // For non-main groups that require a trigger, an allowed message must
+  // also contain it.
+  const allowedMessages = filterAllowedMessages(
+    chatJid,
+    missedMessages,
+    isMainGroup,
+  );
+  const needsTrigger = !isMainGroup && group.requiresTrigger !== false;
+  const canStart = allowedMessages.some(
+    (m) => !needsTrigger || TRIGGER_PATTERN.test(m.content.trim()),
+  );
+  if (!canStart) return true;
 
-  const prompt = formatMessages(missedMessages);
+  const prompt = formatMessages(allowedMessages);
 
   // Advance cursor so the piping path in startMessageLoop won't re-fetch
   // these messages. Save the old cursor so we can roll back on error.
@@ -153,7 +173,7 @@ async function processGroupMessages(chatJid: string): Promise<boolean> {
   saveState();
 
   logger.info(
-    { group: group.name, messageCount: missedMessages.length },
+    { group: group.name, messageCount: allowedMessages.length },
     'Processing messages',
   );
 
@@ -340,15 +360,15 @@ async function startMessageLoop(): Promise<void> {
           const isMainGroup = group.folder === MAIN_GROUP_FOLDER;
           const needsTrigger = !isMainGroup && group.requiresTrigger !== false;
 
-          // For non-main groups, only act on trigger messages.
-          // Non-trigger messages accumulate in DB and get pulled as
-          // context when a trigger eventually arrives.
-          if (needsTrigger) {
-            const hasTrigger = groupMessages.some((m) =>
-              TRIGGER_PATTERN.test(m.content.trim()),
-            );
-            if (!hasTrigger) continue;
-          }
+          // Only act on messages from allowed senders and, for non-main
+          // groups, only on trigger messages. Everything else accumulates
+          // in DB and gets pulled as context when a trigger eventually arrives.
+          const canStart = filterAllowedMessages(
+            chatJid,
+            groupMessages,
+            isMainGroup,
+          ).some((m) => !needsTrigger || TRIGGER_PATTERN.test(m.content.trim()));
+          if (!canStart) continue;
 
           // Pull all messages since lastAgentTimestamp so non-trigger
           // context that accumulated between triggers is included.
@@ -357,8 +377,12 @@ async function startMessageLoop(): Promise<void> {
             lastAgentTimestamp[chatJid] || '',
             ASSISTANT_NAME,
           );
-          const messagesToSend =
-            allPending.length > 0 ? allPending : groupMessages;
+          const pending = allPending.length > 0 ? allPending : groupMessages;
+          const messagesToSend = filterAllowedMessages(
+            chatJid,
+            pending,
+            isMainGroup,
+          );
           const formatted = formatMessages(messagesToSend);
 
           if (queue.sendMessage(chatJid, formatted)) {
@@ -366,8 +390,7 @@ async function startMessageLoop(): Promise<void> {
               { chatJid, count: messagesToSend.length },
               'Piped messages to active container',
             );
-            lastAgentTimestamp[chatJid] =
-              messagesToSend[messagesToSend.length - 1].timestamp;
+            lastAgentTimestamp[chatJid] = pending[pending.length - 1].timestamp;
             saveState();
             // Show typing indicator while the container processes the piped message
             channel.setTyping?.(chatJid, true)?.catch((err) =>
@@ -439,7 +462,7 @@ async function main(): Promise<void> {
   await whatsapp.connect();
 
   // Start subsystems (independently of connection handler)
-  startSchedulerLoop({
+  const schedulerDeps: SchedulerDependencies = {
     registeredGroups: () => registeredGroups,
     getSessions: () => sessions,
     queue,
@@ -453,7 +476,8 @@ async function main(): Promise<void> {
       const text = formatOutbound(rawText);
       if (text) await channel.sendMessage(jid, text);
     },
-  });
+  };
+  startSchedulerLoop(schedulerDeps);
   startIpcWatcher({
     sendMessage: (jid, text) => {
       const channel = findChannel(channels, jid);
@@ -462,6 +486,25 @@ async function main(): Promise<void> {
     },
     registeredGroups: () => registeredGroups,
     registerGroup,
+    runTaskNow: (task) => runTaskNow(task, schedulerDeps),
+    pauseDispatch: (jid) => {
+      if (jid) {
+        queue.pauseGroup(jid);
+      } else {
+        // Main stays live during a global pause so it can resume
+        queue.pauseAll(
+          Object.keys(registeredGroups).filter(
+            (j) => registeredGroups[j].folder === MAIN_GROUP_FOLDER,
+          ),
+        );
+      }
+      saveState();
+    },
+    resumeDispatch: (jid) => {
+      if (jid) queue.resumeGroup(jid);
+      else queue.resumeAll();
+      saveState();
+    },
     syncGroupMetadata: (force) => whatsapp?.syncGroupMetadata(force) ?? Promise.resolve(),
     getAvailableGroups,
     writeGroupsSnapshot: (gf, im, ag, rj) => writeGroupsSnapshot(gf, im, ag, rj),
@@ -35,9 +35,13 @@ const THIRD_GROUP: RegisteredGroup = {
 
 let groups: Record<string, RegisteredGroup>;
 let deps: IpcDeps;
+let ranTasks: string[];
+let dispatchCalls: string[];
 
 beforeEach(() => {
   _initTestDatabase();
+  ranTasks = [];
+  dispatchCalls = [];
 
   groups = {
     'main@g.us': MAIN_GROUP,
@@ -58,6 +62,15 @@ beforeEach(() => {
       setRegisteredGroup(jid, group);
       // Mock the fs.mkdirSync that registerGroup does
     },
+    runTaskNow: (task) => {
+      ranTasks.push(task.id);
+    },
+    pauseDispatch: (jid) => {
+      dispatchCalls.push(`pause:${jid ?? 'all'}`);
+    },
+    resumeDispatch: (jid) => {
+      dispatchCalls.push(`resume:${jid ?? 'all'}`);
+    },
     syncGroupMetadata: async () => {},
     getAvailableGroups: () => [],
     writeGroupsSnapshot: () => {},
@@ -223,6 +236,52 @@ describe('resume_task authorization', () => {
   });
 });
 
+// --- run_task authorization ---
+
+describe('run_task authorization', () => {
+  beforeEach(() => {
+    createTask({
+      id: 'task-other',
+      group_folder: 'other-group',
+      chat_jid: 'other@g.us',
+      prompt: 'other task',
+      schedule_type: 'cron',
+      schedule_value: '0 9 * * *',
+      context_mode: 'isolated',
+      next_run: '2025-06-01T09:00:00.000Z',
+      status: 'active',
+      created_at: '2024-01-01T00:00:00.000Z',
+    });
+  });
+
+  it('main group can run any task', async () => {
+    await processTaskIpc({ type: 'run_task', taskId: 'task-other' }, 'main', true, deps);
+    expect(ranTasks).toEqual(['task-other']);
+  });
+
+  it('non-main group can run its own task', async () => {
+    await processTaskIpc({ type: 'run_task', taskId: 'task-other' }, 'other-group', false, deps);
+    expect(ranTasks).toEqual(['task-other']);
+  });
+
+  it('non-main group cannot run another groups task', async () => {
+    await processTaskIpc({ type: 'run_task', taskId: 'task-other' }, 'third-group', false, deps);
+    expect(ranTasks).toEqual([]);
+  });
+
+  it('does not change the task schedule', async () => {
+    await processTaskIpc({ type: 'run_task', taskId: 'task-other' }, 'main', true, deps);
+    const task = getTaskById('task-other')!;
+    expect(task.next_run).toBe('2025-06-01T09:00:00.000Z');
+    expect(task.status).toBe('active');
+  });
+
+  it('ignores unknown task IDs', async () => {
+    await processTaskIpc({ type: 'run_task', taskId: 'nope' }, 'main', true, deps);
+    expect(ranTasks).toEqual([]);
+  });
+});
+
 // --- cancel_task authorization ---
 
 describe('cancel_task authorization', () => {
@@ -313,6 +372,68 @@ describe('refresh_groups authorization', () => {
   });
 });
 
+// --- pause_dispatch / resume_dispatch authorization ---
+
+describe('dispatch control authorization', () => {
+  it('main group can pause and resume all groups', async () => {
+    await processTaskIpc({ type: 'pause_dispatch' }, 'main', true, deps);
+    await processTaskIpc({ type: 'resume_dispatch' }, 'main', true, deps);
+
+    expect(dispatchCalls).toEqual(['pause:all', 'resume:all']);
+  });
+
+  it('main group can pause and resume a single group', async () => {
+    await processTaskIpc(
+      { type: 'pause_dispatch', jid: 'other@g.us' },
+      'main',
+      true,
+      deps,
+    );
+    await processTaskIpc(
+      { type: 'resume_dispatch', jid: 'other@g.us' },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(dispatchCalls).toEqual(['pause:other@g.us', 'resume:other@g.us']);
+  });
+
+  it('non-main group cannot pause or resume dispatching', async () => {
+    await processTaskIpc({ type: 'pause_dispatch' }, 'other-group', false, deps);
+    await processTaskIpc(
+      { type: 'resume_dispatch', jid: 'other@g.us' },
+      'other-group',
+      false,
+      deps,
+    );
+
+    expect(dispatchCalls).toEqual([]);
+  });
+
+  it('main group cannot be paused', async () => {
+    await processTaskIpc(
+      { type: 'pause_dispatch', jid: 'main@g.us' },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(dispatchCalls).toEqual([]);
+  });
+
+  it('ignores unregistered groups', async () => {
+    await processTaskIpc(
+      { type: 'pause_dispatch', jid: 'unknown@g.us' },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(dispatchCalls).toEqual([]);
+  });
+});
+
 // --- IPC message authorization ---
 // Tests the authorization pattern from startIpcWatcher (ipc.ts).
 // The logic: isMain || (targetGroup && targetGroup.folder === sourceGroup)
@@ -454,6 +575,23 @@ describe('schedule_task schedule types', () => {
     expect(getAllTasks()).toHaveLength(0);
   });
 
+  it('rejects unknown schedule type', async () => {
+    await processTaskIpc(
+      {
+        type: 'schedule_task',
+        prompt: 'bad type',
+        schedule_type: 'weekly',
+        schedule_value: 'monday',
+        targetJid: 'other@g.us',
+      },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(getAllTasks()).toHaveLength(0);
+  });
+
   it('rejects invalid once timestamp', async () => {
     await processTaskIpc(
       {
@@ -591,4 +729,58 @@ describe('register_group success', () => {
 
     expect(getRegisteredGroup('partial@g.us')).toBeUndefined();
   });
+
+  it('register_group stores quiet hours', async () => {
+    await processTaskIpc(
+      {
+        type: 'register_group',
+        jid: 'quiet@g.us',
+        name: 'Quiet',
+        folder: 'quiet-group',
+        trigger: '@Andy',
+        quietHours: '23:00-07:00',
+      },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(getRegisteredGroup('quiet@g.us')!.quietHours).toBe('23:00-07:00');
+  });
+
+  it('register_group treats blank quiet hours as unset', async () => {
+    await processTaskIpc(
+      {
+        type: 'register_group',
+        jid: 'quiet@g.us',
+        name: 'Quiet',
+        folder: 'quiet-group',
+        trigger: '@Andy',
+        quietHours: '  ',
+      },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(getRegisteredGroup('quiet@g.us')!.quietHours).toBeUndefined();
+  });
+
+  it('register_group rejects invalid quiet hours', async () => {
+    await processTaskIpc(
+      {
+        type: 'register_group',
+        jid: 'quiet@g.us',
+        name: 'Quiet',
+        folder: 'quiet-group',
+        trigger: '@Andy',
+        quietHours: '11pm-7am',
+      },
+      'main',
+      true,
+      deps,
+    );
+
+    expect(getRegisteredGroup('quiet@g.us')).toBeUndefined();
+  });
 });
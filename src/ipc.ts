@@ -1,23 +1,24 @@
 import fs from 'fs';
 import path from 'path';
 
-import { CronExpressionParser } from 'cron-parser';
-
 import {
   DATA_DIR,
   IPC_POLL_INTERVAL,
   MAIN_GROUP_FOLDER,
-  TIMEZONE,
 } from './config.js';
 import { AvailableGroup } from './container-runner.js';
 import { createTask, deleteTask, getTaskById, updateTask } from './db.js';
 import { logger } from './logger.js';
-import { RegisteredGroup } from './types.js';
+import { isValidQuietHours, validateSchedule } from './task-scheduler.js';
+import { RegisteredGroup, ScheduledTask } from './types.js';
 
 export interface IpcDeps {
   sendMessage: (jid: string, text: string) => Promise<void>;
   registeredGroups: () => Record<string, RegisteredGroup>;
   registerGroup: (jid: string, group: RegisteredGroup) => void;
+  runTaskNow: (task: ScheduledTask) => void;
+  pauseDispatch: (jid?: string) => void;
+  resumeDispatch: (jid?: string) => void;
   syncGroupMetadata: (force: boolean) => Promise<void>;
   getAvailableGroups: () => AvailableGroup[];
   writeGroupsSnapshot: (
@@ -162,12 +163,13 @@ export async function processTaskIpc(
     groupFolder?: string;
     chatJid?: string;
     targetJid?: string;
-    // For register_group
+    // For register_group (and pause/resume_dispatch, where omitted means all groups)
     jid?: string;
     name?: string;
     folder?: string;
     trigger?: string;
     requiresTrigger?: boolean;
+    quietHours?: string;
     containerConfig?: RegisteredGroup['containerConfig'];
   },
   sourceGroup: string, // Verified identity from IPC directory
@@ -209,41 +211,15 @@ export async function processTaskIpc(
 
         const scheduleType = data.schedule_type as 'cron' | 'interval' | 'once';
 
-        let nextRun: string | null = null;
-        if (scheduleType === 'cron') {
-          try {
-            const interval = CronExpressionParser.parse(data.schedule_value, {
-              tz: TIMEZONE,
-            });
-            nextRun = interval.next().toISOString();
-          } catch {
-            logger.warn(
-              { scheduleValue: data.schedule_value },
-              'Invalid cron expression',
-            );
-            break;
-          }
-        } else if (scheduleType === 'interval') {
-          const ms = parseInt(data.schedule_value, 10);
-          if (isNaN(ms) || ms <= 0) {
-            logger.warn(
-              { scheduleValue: data.schedule_value },
-              'Invalid interval',
-            );
-            break;
-          }
-          nextRun = new Date(Date.now() + ms).toISOString();
-        } else if (scheduleType === 'once') {
-          const scheduled = new Date(data.schedule_value);
-          if (isNaN(scheduled.getTime())) {
-            logger.warn(
-              { scheduleValue: data.schedule_value },
-              'Invalid timestamp',
-            );
-            break;
-          }
-          nextRun = scheduled.toISOString();
+        const validation = validateSchedule(scheduleType, data.schedule_value);
+        if (!validation.valid) {
+          logger.warn(
+            { scheduleType, scheduleValue: data.schedule_value },
+            validation.error,
+          );
+          break;
         }
+        const nextRun = validation.nextRun;
 
         const taskId = `task-${Date.now()}-${Math.random().toString(36).slice(2, 8)}`;
         const contextMode =
@@ -263,7 +239,14 @@ export async function processTaskIpc(
           created_at: new Date().toISOString(),
         });
         logger.info(
-          { taskId, sourceGroup, targetFolder, contextMode },
+          {
+            taskId,
+            sourceGroup,
+            targetFolder,
+            contextMode,
+            scheduleType,
+            scheduleValue: data.schedule_value,
+          },
           'Task created via IPC',
         );
       }
@@ -323,6 +306,24 @@ export async function processTaskIpc(
       }
       break;
 
+    case 'run_task':
+      if (data.taskId) {
+        const task = getTaskById(data.taskId);
+        if (task && (isMain || task.group_folder === sourceGroup)) {
+          deps.runTaskNow(task);
+          logger.info(
+            { taskId: data.taskId, sourceGroup },
+            'Task run requested via IPC',
+          );
+        } else {
+          logger.warn(
+            { taskId: data.taskId, sourceGroup },
+            'Unauthorized task run attempt',
+          );
+        }
+      }
+      break;
+
     case 'refresh_groups':
       // Only main group can request a refresh
       if (isMain) {
@@ -356,6 +357,15 @@ export async function processTaskIpc(
         );
         break;
       }
+      // Blank means "use the global window", same as omitting it
+      const quietHours = data.quietHours?.trim() || undefined;
+      if (quietHours && !isValidQuietHours(quietHours)) {
+        logger.warn(
+          { quietHours },
+          'Invalid register_group request - quietHours must be "HH:MM-HH:MM" or "off"',
+        );
+        break;
+      }
       if (data.jid && data.name && data.folder && data.trigger) {
         deps.registerGroup(data.jid, {
           name: data.name,
@@ -364,6 +374,7 @@ export async function processTaskIpc(
           added_at: new Date().toISOString(),
           containerConfig: data.containerConfig,
           requiresTrigger: data.requiresTrigger,
+          quietHours,
         });
       } else {
         logger.warn(
@@ -373,6 +384,39 @@ export async function processTaskIpc(
       }
       break;
 
+    case 'pause_dispatch':
+    case 'resume_dispatch':
+      // Only main group can pause or resume dispatching
+      if (!isMain) {
+        logger.warn(
+          { sourceGroup, type: data.type },
+          'Unauthorized dispatch control attempt blocked',
+        );
+        break;
+      }
+      if (data.jid && !registeredGroups[data.jid]) {
+        logger.warn(
+          { jid: data.jid, type: data.type },
+          'Dispatch control for unregistered group ignored',
+        );
+        break;
+      }
+      if (data.type === 'pause_dispatch') {
+        // Main must stay live so it can resume everything else
+        if (data.jid && registeredGroups[data.jid].folder === MAIN_GROUP_FOLDER) {
+          logger.warn({ jid: data.jid }, 'Main group cannot be paused');
+          break;
+        }
+        deps.pauseDispatch(data.jid);
+      } else {
+        deps.resumeDispatch(data.jid);
+      }
+      logger.info(
+        { jid: data.jid ?? 'all', type: data.type, sourceGroup },
+        'Dispatch control applied via IPC',
+      );
+      break;
+
     default:
       logger.warn({ type: data.type }, 'Unknown IPC task type');
   }
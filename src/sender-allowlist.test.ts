@@ -0,0 +1,189 @@
+import fs from 'fs';
+import path from 'path';
+import { describe, it, expect, afterEach, beforeEach, vi } from 'vitest';
+
+// Point the loader at a temp file instead of ~/.config
+const { ALLOWLIST_PATH } = vi.hoisted(() => ({
+  ALLOWLIST_PATH: `${process.env.TMPDIR || '/tmp'}/nanoclaw-sender-allowlist-${process.pid}.json`,
+}));
+
+vi.mock('./config.js', () => ({
+  SENDER_ALLOWLIST_PATH: ALLOWLIST_PATH,
+}));
+
+import { filterAllowedMessages, isSenderAllowed } from './sender-allowlist.js';
+import { NewMessage, SenderAllowlist } from './types.js';
+
+const ALLOWLIST: SenderAllowlist = {
+  chats: {
+    'family@g.us': ['alice@s.whatsapp.net', 'bob@s.whatsapp.net'],
+  },
+};
+
+describe('isSenderAllowed', () => {
+  it('allows everyone when there is no allowlist', () => {
+    expect(isSenderAllowed('family@g.us', 'mallory@s.whatsapp.net', null)).toBe(
+      true,
+    );
+  });
+
+  it('allows listed senders in a listed chat', () => {
+    expect(
+      isSenderAllowed('family@g.us', 'alice@s.whatsapp.net', ALLOWLIST),
+    ).toBe(true);
+  });
+
+  it('blocks unlisted senders in a listed chat', () => {
+    expect(
+      isSenderAllowed('family@g.us', 'mallory@s.whatsapp.net', ALLOWLIST),
+    ).toBe(false);
+  });
+
+  it('allows anyone in chats that are not listed', () => {
+    expect(
+      isSenderAllowed('other@g.us', 'mallory@s.whatsapp.net', ALLOWLIST),
+    ).toBe(true);
+  });
+
+  it('applies the default list to chats that are not listed', () => {
+    const withDefault = { ...ALLOWLIST, default: ['alice@s.whatsapp.net'] };
+    expect(
+      isSenderAllowed('other@g.us', 'alice@s.whatsapp.net', withDefault),
+    ).toBe(true);
+    expect(
+      isSenderAllowed('other@g.us', 'mallory@s.whatsapp.net', withDefault),
+    ).toBe(false);
+  });
+
+  it('prefers the chat entry over the default list', () => {
+    const withDefault = { ...ALLOWLIST, default: [] };
+    expect(
+      isSenderAllowed('family@g.us', 'bob@s.whatsapp.net', withDefault),
+    ).toBe(true);
+  });
+});
+
+// --- filterAllowedMessages ---
+
+function msg(sender: string, isFromMe = false): NewMessage {
+  return {
+    id: sender,
+    chat_jid: 'family@g.us',
+    sender,
+    sender_name: sender.split('@')[0],
+    content: '@Andy cancel all tasks',
+    timestamp: '2024-01-01T00:00:00.000Z',
+    is_from_me: isFromMe,
+  };
+}
+
+describe('filterAllowedMessages', () => {
+  it('drops messages from senders who are not allowed', () => {
+    const messages = [
+      msg('alice@s.whatsapp.net'),
+      msg('mallory@s.whatsapp.net'),
+    ];
+    expect(
+      filterAllowedMessages('family@g.us', messages, false, ALLOWLIST).map(
+        (m) => m.sender,
+      ),
+    ).toEqual(['alice@s.whatsapp.net']);
+  });
+
+  it('always keeps the owner\'s own messages', () => {
+    const messages = [msg('owner@s.whatsapp.net', true)];
+    expect(
+      filterAllowedMessages('family@g.us', messages, false, ALLOWLIST),
+    ).toHaveLength(1);
+  });
+
+  it('never filters the main group', () => {
+    const messages = [msg('mallory@s.whatsapp.net')];
+    const blockAll = { chats: {}, default: [] };
+    expect(
+      filterAllowedMessages('main@g.us', messages, true, blockAll),
+    ).toHaveLength(1);
+  });
+});
+
+// --- loadSenderAllowlist ---
+
+describe('loadSenderAllowlist', () => {
+  // The loader caches for the process lifetime, so each case re-imports
+  // a fresh copy of the module.
+  async function loadFresh(contents: string) {
+    fs.mkdirSync(path.dirname(ALLOWLIST_PATH), { recursive: true });
+    fs.writeFileSync(ALLOWLIST_PATH, contents);
+    vi.resetModules();
+    return import('./sender-allowlist.js');
+  }
+
+  beforeEach(() => {
+    fs.rmSync(ALLOWLIST_PATH, { force: true });
+  });
+
+  afterEach(() => {
+    fs.rmSync(ALLOWLIST_PATH, { force: true });
+  });
+
+  it('returns null when the file does not exist', async () => {
+    vi.resetModules();
+    const mod = await import('./sender-allowlist.js');
+    expect(mod.loadSenderAllowlist()).toBeNull();
+    expect(mod.isSenderAllowed('family@g.us', 'mallory@s.whatsapp.net')).toBe(
+      true,
+    );
+  });
+
+  it('loads the allowlist from the external config path', async () => {
+    const mod = await loadFresh(JSON.stringify(ALLOWLIST));
+    expect(mod.loadSenderAllowlist()).toEqual(ALLOWLIST);
+    expect(mod.isSenderAllowed('family@g.us', 'mallory@s.whatsapp.net')).toBe(
+      false,
+    );
+  });
+
+  it('fails closed on invalid JSON', async () => {
+    const mod = await loadFresh('{ not json');
+    expect(mod.loadSenderAllowlist()).toEqual({ chats: {}, default: [] });
+    expect(mod.isSenderAllowed('other@g.us', 'alice@s.whatsapp.net')).toBe(
+      false,
+    );
+  });
+
+  it('fails closed when a chat entry is not an array', async () => {
+    const mod = await loadFresh(
+      JSON.stringify({ chats: { 'family@g.us': 'alice@s.whatsapp.net' } }),
+    );
+    expect(mod.isSenderAllowed('family@g.us', 'alice@s.whatsapp.net')).toBe(
+      false,
+    );
+  });
+
+  it('fails closed when default is not an array', async () => {
+    const mod = await loadFresh(
+      JSON.stringify({ chats: {}, default: 'alice@s.whatsapp.net' }),
+    );
+    expect(mod.isSenderAllowed('other@g.us', 'alice@s.whatsapp.net')).toBe(
+      false,
+    );
+  });
+
+  it('still lets the owner and main group through when failed closed', async () => {
+    const mod = await loadFresh('{ not json');
+    expect(
+      mod.filterAllowedMessages(
+        'family@g.us',
+        [msg('owner@s.whatsapp.net', true)],
+        false,
+      ),
+    ).toHaveLength(1);
+    expect(
+      mod.filterAllowedMessages(
+        'main@g.us',
+        [msg('alice@s.whatsapp.net')],
+        true,
+      ),
+    ).toHaveLength(1);
+  });
+});
@@ -0,0 +1,103 @@
+/**
+ * Sender Allowlist for NanoClaw
+ *
+ * Restricts which senders can trigger the agent in a chat, so other group
+ * members (or forwarded bots) can't start runs or change schedules.
+ * Messages from other senders are left out of the agent prompt entirely.
+ * The main group and the owner's own messages are never restricted.
+ * Stored OUTSIDE the project root so container agents can't modify it.
+ *
+ * Allowlist location: ~/.config/nanoclaw/sender-allowlist.json
+ */
+import fs from 'fs';
+
+import { SENDER_ALLOWLIST_PATH } from './config.js';
+import { logger } from './logger.js';
+import { NewMessage, SenderAllowlist } from './types.js';
+
+// Cache the allowlist in memory - only reloads on process restart
+let cachedAllowlist: SenderAllowlist | null = null;
+let allowlistLoaded = false;
+
+/**
+ * Load the sender allowlist from the external config location.
+ * Returns null if the file doesn't exist, meaning anyone may trigger.
+ * An invalid file yields an allowlist that blocks every sender.
+ * Result is cached in memory for the lifetime of the process.
+ */
+export function loadSenderAllowlist(): SenderAllowlist | null {
+  if (allowlistLoaded) return cachedAllowlist;
+  allowlistLoaded = true;
+
+  if (!fs.existsSync(SENDER_ALLOWLIST_PATH)) return null;
+
+  try {
+    const allowlist = JSON.parse(
+      fs.readFileSync(SENDER_ALLOWLIST_PATH, 'utf-8'),
+    ) as SenderAllowlist;
+
+    if (
+      !allowlist.chats ||
+      typeof allowlist.chats !== 'object' ||
+      Object.values(allowlist.chats).some((senders) => !Array.isArray(senders))
+    ) {
+      throw new Error('chats must map chat JIDs to arrays of sender JIDs');
+    }
+    if (allowlist.default !== undefined && !Array.isArray(allowlist.default)) {
+      throw new Error('default must be an array of sender JIDs');
+    }
+
+    cachedAllowlist = allowlist;
+    logger.info(
+      {
+        path: SENDER_ALLOWLIST_PATH,
+        chats: Object.keys(allowlist.chats).length,
+      },
+      'Sender allowlist loaded',
+    );
+  } catch (err) {
+    // Fail closed: a broken allowlist should not silently open every chat
+    cachedAllowlist = { chats: {}, default: [] };
+    logger.error(
+      {
+        path: SENDER_ALLOWLIST_PATH,
+        error: err instanceof Error ? err.message : String(err),
+      },
+      'Failed to load sender allowlist - triggers outside the main group are BLOCKED until it is fixed',
+    );
+  }
+
+  return cachedAllowlist;
+}
+
+/**
+ * Check whether a sender may trigger the agent in a chat.
+ * Falls back to the default list for unlisted chats; no list means anyone.
+ */
+export function isSenderAllowed(
+  chatJid: string,
+  sender: string,
+  allowlist: SenderAllowlist | null = loadSenderAllowlist(),
+): boolean {
+  if (!allowlist) return true;
+  const allowed = allowlist.chats[chatJid] ?? allowlist.default;
+  if (!allowed) return true;
+  return allowed.includes(sender);
+}
+
+/**
+ * Keep only the messages the agent may see in a chat.
+ * The main group and is_from_me messages always pass, so a strict or
+ * broken allowlist can never lock the owner out.
+ */
+export function filterAllowedMessages(
+  chatJid: string,
+  messages: NewMessage[],
+  isMainGroup: boolean,
+  allowlist: SenderAllowlist | null = loadSenderAllowlist(),
+): NewMessage[] {
+  if (isMainGroup) return messages;
+  return messages.filter(
+    (m) => m.is_from_me || isSenderAllowed(chatJid, m.sender, allowlist),
+  );
+}
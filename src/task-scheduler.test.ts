@@ -0,0 +1,302 @@
+import { describe, it, expect, afterEach, beforeEach, vi } from 'vitest';
+
+vi.mock('./config.js', async () => {
+  const actual =
+    await vi.importActual<typeof import('./config.js')>('./config.js');
+  return {
+    ...actual,
+    GROUPS_DIR: '/tmp/nanoclaw-test-groups',
+    QUIET_HOURS: '',
+    TIMEZONE: 'UTC',
+  };
+});
+
+vi.mock('./container-runner.js', () => ({
+  runContainerAgent: vi.fn(async () => ({
+    status: 'success',
+    result: 'Done',
+  })),
+  writeTasksSnapshot: vi.fn(),
+}));
+
+import {
+  _initTestDatabase,
+  createTask,
+  getTaskById,
+  getTaskRunLogs,
+} from './db.js';
+import { runContainerAgent } from './container-runner.js';
+import { GroupQueue } from './group-queue.js';
+import {
+  SchedulerDependencies,
+  isQuietTime,
+  isValidQuietHours,
+  runTaskNow,
+  startSchedulerLoop,
+  validateSchedule,
+} from './task-scheduler.js';
+
+// --- validateSchedule ---
+
+describe('validateSchedule', () => {
+  it('accepts a cron expression and computes next run', () => {
+    const result = validateSchedule('cron', '0 9 * * *');
+    expect(result.valid).toBe(true);
+    if (result.valid) {
+      expect(new Date(result.nextRun!).getTime()).toBeGreaterThan(Date.now());
+    }
+  });
+
+  it('explains why a cron expression is invalid', () => {
+    const result = validateSchedule('cron', 'not a cron');
+    expect(result.valid).toBe(false);
+    if (!result.valid) {
+      expect(result.error).toContain('Invalid cron expression "not a cron"');
+    }
+  });
+
+  it('accepts a positive interval', () => {
+    const before = Date.now();
+    const result = validateSchedule('interval', '300000');
+    expect(result.valid).toBe(true);
+    if (result.valid) {
+      expect(new Date(result.nextRun!).getTime()).toBeGreaterThanOrEqual(
+        before + 300000,
+      );
+    }
+  });
+
+  it('rejects zero, negative, fractional, and non-numeric intervals', () => {
+    for (const value of ['0', '-5', '1.5', 'abc', '300000abc', '3.6e6', '0x10']) {
+      expect(validateSchedule('interval', value).valid).toBe(false);
+    }
+  });
+
+  it('accepts a once timestamp', () => {
+    const result = validateSchedule('once', '2026-02-01T15:30:00.000Z');
+    expect(result).toEqual({
+      valid: true,
+      nextRun: '2026-02-01T15:30:00.000Z',
+    });
+  });
+
+  it('rejects an invalid once timestamp', () => {
+    expect(validateSchedule('once', 'tomorrow-ish').valid).toBe(false);
+  });
+
+  it('rejects unknown schedule types', () => {
+    const result = validateSchedule('weekly', 'monday');
+    expect(result.valid).toBe(false);
+    if (!result.valid) {
+      expect(result.error).toContain('Unknown schedule type "weekly"');
+    }
+  });
+});
+
+// --- runTaskNow ---
+
+describe('runTaskNow', () => {
+  const NEXT_RUN = '2099-01-01T09:00:00.000Z';
+  let queued: Array<{ taskId: string; run: Promise<void> }>;
+  let deps: SchedulerDependencies;
+
+  beforeEach(() => {
+    _initTestDatabase();
+    vi.mocked(runContainerAgent).mockClear();
+    queued = [];
+
+    // Run queued work immediately and keep the promise so tests can await it
+    const queue = {
+      enqueueTask: (_jid: string, taskId: string, fn: () => Promise<void>) => {
+        queued.push({ taskId, run: fn() });
+      },
+      closeStdin: vi.fn(),
+      notifyIdle: vi.fn(),
+    } as unknown as GroupQueue;
+
+    deps = {
+      registeredGroups: () => ({
+        'other@g.us': {
+          name: 'Other',
+          folder: 'other-group',
+          trigger: '@Andy',
+          added_at: '2024-01-01T00:00:00.000Z',
+        },
+      }),
+      getSessions: () => ({}),
+      queue,
+      onProcess: () => {},
+      sendMessage: async () => {},
+    };
+  });
+
+  function createScheduledTask(
+    id: string,
+    scheduleType: 'cron' | 'interval',
+    scheduleValue: string,
+    status: 'active' | 'paused' = 'active',
+  ) {
+    createTask({
+      id,
+      group_folder: 'other-group',
+      chat_jid: 'other@g.us',
+      prompt: 'check the weather',
+      schedule_type: scheduleType,
+      schedule_value: scheduleValue,
+      context_mode: 'isolated',
+      next_run: NEXT_RUN,
+      status,
+      created_at: '2024-01-01T00:00:00.000Z',
+    });
+  }
+
+  async function runNow(id: string) {
+    runTaskNow(getTaskById(id)!, deps);
+    await Promise.all(queued.map((q) => q.run));
+  }
+
+  it('runs a cron task without changing its schedule', async () => {
+    createScheduledTask('task-cron', 'cron', '0 9 * * *');
+
+    await runNow('task-cron');
+
+    expect(runContainerAgent).toHaveBeenCalledTimes(1);
+    const task = getTaskById('task-cron')!;
+    expect(task.next_run).toBe(NEXT_RUN);
+    expect(task.status).toBe('active');
+    expect(task.last_result).toBe('Done');
+
+    const logs = getTaskRunLogs('task-cron');
+    expect(logs).toHaveLength(1);
+    expect(logs[0]).toMatchObject({ status: 'success', result: 'Done' });
+  });
+
+  it('runs an interval task without changing its schedule', async () => {
+    createScheduledTask('task-interval', 'interval', '3600000');
+
+    await runNow('task-interval');
+
+    const task = getTaskById('task-interval')!;
+    expect(task.next_run).toBe(NEXT_RUN);
+    expect(task.status).toBe('active');
+    expect(getTaskRunLogs('task-interval')).toHaveLength(1);
+  });
+
+  it('leaves a paused task paused', async () => {
+    createScheduledTask('task-paused', 'cron', '0 9 * * *', 'paused');
+
+    await runNow('task-paused');
+
+    const task = getTaskById('task-paused')!;
+    expect(task.next_run).toBe(NEXT_RUN);
+    expect(task.status).toBe('paused');
+  });
+
+  it('queues under a key distinct from scheduled runs', async () => {
+    createScheduledTask('task-key', 'cron', '0 9 * * *');
+
+    await runNow('task-key');
+
+    expect(queued.map((q) => q.taskId)).toEqual(['task-key:manual']);
+  });
+});
+
+// --- Quiet hours ---
+
+describe('isQuietTime', () => {
+  const at = (time: string) => new Date(`2026-01-01T${time}:00.000Z`);
+
+  it('matches a same-day window', () => {
+    expect(isQuietTime('13:00-14:30', at('13:00'))).toBe(true);
+    expect(isQuietTime('13:00-14:30', at('14:29'))).toBe(true);
+    expect(isQuietTime('13:00-14:30', at('14:30'))).toBe(false);
+    expect(isQuietTime('13:00-14:30', at('12:59'))).toBe(false);
+  });
+
+  it('matches a window that wraps past midnight', () => {
+    expect(isQuietTime('23:00-07:00', at('23:30'))).toBe(true);
+    expect(isQuietTime('23:00-07:00', at('03:00'))).toBe(true);
+    expect(isQuietTime('23:00-07:00', at('07:00'))).toBe(false);
+    expect(isQuietTime('23:00-07:00', at('12:00'))).toBe(false);
+  });
+
+  it('never matches empty, off, or malformed specs', () => {
+    for (const spec of [undefined, '', 'off', '25:00-07:00', 'night']) {
+      expect(isQuietTime(spec, at('03:00'))).toBe(false);
+    }
+  });
+
+  it('validates specs', () => {
+    expect(isValidQuietHours('23:00-07:00')).toBe(true);
+    expect(isValidQuietHours('off')).toBe(true);
+    expect(isValidQuietHours('23:00')).toBe(false);
+    expect(isValidQuietHours('9pm-7am')).toBe(false);
+  });
+});
+
+describe('startSchedulerLoop quiet hours', () => {
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('defers due tasks during quiet hours and releases them after', async () => {
+    vi.useFakeTimers();
+    vi.setSystemTime(new Date('2026-01-01T03:00:00.000Z'));
+    _initTestDatabase();
+
+    for (const [id, folder, jid] of [
+      ['task-quiet', 'family', 'family@g.us'],
+      ['task-loud', 'work', 'work@g.us'],
+    ]) {
+      createTask({
+        id,
+        group_folder: folder,
+        chat_jid: jid,
+        prompt: 'ping',
+        schedule_type: 'interval',
+        schedule_value: '3600000',
+        context_mode: 'isolated',
+        next_run: '2026-01-01T02:00:00.000Z',
+        status: 'active',
+        created_at: '2024-01-01T00:00:00.000Z',
+      });
+    }
+
+    const enqueued: string[] = [];
+    const queue = {
+      enqueueTask: (_jid: string, taskId: string) => {
+        enqueued.push(taskId);
+      },
+    } as unknown as GroupQueue;
+
+    startSchedulerLoop({
+      registeredGroups: () => ({
+        'family@g.us': {
+          name: 'Family',
+          folder: 'family',
+          trigger: '@Andy',
+          added_at: '2024-01-01T00:00:00.000Z',
+          quietHours: '23:00-07:00',
+        },
+        'work@g.us': {
+          name: 'Work',
+          folder: 'work',
+          trigger: '@Andy',
+          added_at: '2024-01-01T00:00:00.000Z',
+        },
+      }),
+      getSessions: () => ({}),
+      queue,
+      onProcess: () => {},
+      sendMessage: async () => {},
+    });
+
+    expect(enqueued).toEqual(['task-loud']);
+
+    // Window ends at 07:00; the next poll picks up the deferred task
+    vi.setSystemTime(new Date('2026-01-01T07:00:00.000Z'));
+    await vi.advanceTimersByTimeAsync(60000);
+
+    expect(enqueued).toContain('task-quiet');
+  });
+});
@@ -7,6 +7,7 @@ import {
   GROUPS_DIR,
   IDLE_TIMEOUT,
   MAIN_GROUP_FOLDER,
+  QUIET_HOURS,
   SCHEDULER_POLL_INTERVAL,
   TIMEZONE,
 } from './config.js';
@@ -30,9 +31,102 @@ export interface SchedulerDependencies {
   sendMessage: (jid: string, text: string) => Promise<void>;
 }
 
+export type ScheduleValidation =
+  | { valid: true; nextRun: string | null }
+  | { valid: false; error: string };
+
+/**
+ * Validate a schedule and compute its first run time.
+ * Errors are phrased so they can be shown to the user as-is.
+ * The schedule_task MCP tool pre-checks with scheduleError in
+ * container/agent-runner/src/schedule.ts; this is the authoritative check.
+ */
+export function validateSchedule(
+  scheduleType: string,
+  scheduleValue: string,
+): ScheduleValidation {
+  if (scheduleType === 'cron') {
+    try {
+      const interval = CronExpressionParser.parse(scheduleValue, {
+        tz: TIMEZONE,
+      });
+      return { valid: true, nextRun: interval.next().toISOString() };
+    } catch (err) {
+      const reason = err instanceof Error ? err.message : String(err);
+      return {
+        valid: false,
+        error: `Invalid cron expression "${scheduleValue}": ${reason}`,
+      };
+    }
+  }
+  if (scheduleType === 'interval') {
+    // Plain digits only: runTask re-reads the value with parseInt, which
+    // would turn "3.6e6" or "0x10" into a different interval
+    const ms = /^\d+$/.test(scheduleValue) ? parseInt(scheduleValue, 10) : 0;
+    if (ms <= 0) {
+      return {
+        valid: false,
+        error: `Invalid interval "${scheduleValue}": must be a positive whole number of milliseconds`,
+      };
+    }
+    return { valid: true, nextRun: new Date(Date.now() + ms).toISOString() };
+  }
+  if (scheduleType === 'once') {
+    const scheduled = new Date(scheduleValue);
+    if (isNaN(scheduled.getTime())) {
+      return {
+        valid: false,
+        error: `Invalid timestamp "${scheduleValue}": use ISO 8601 like "2026-02-01T15:30:00"`,
+      };
+    }
+    return { valid: true, nextRun: scheduled.toISOString() };
+  }
+  return {
+    valid: false,
+    error: `Unknown schedule type "${scheduleType}": use cron, interval, or once`,
+  };
+}
+
+const QUIET_HOURS_PATTERN = /^([01]?\d|2[0-3]):([0-5]\d)-([01]?\d|2[0-3]):([0-5]\d)$/;
+
+export function isValidQuietHours(spec: string): boolean {
+  return spec === 'off' || QUIET_HOURS_PATTERN.test(spec.trim());
+}
+
+/**
+ * Check whether a time falls inside a quiet-hours window like "23:00-07:00",
+ * read in TIMEZONE. Windows may wrap past midnight; the end is exclusive.
+ * Empty, "off", and malformed specs never match.
+ */
+export function isQuietTime(
+  spec: string | undefined,
+  now: Date = new Date(),
+): boolean {
+  const match = spec?.trim().match(QUIET_HOURS_PATTERN);
+  if (!match) return false;
+  const [startH, startM, endH, endM] = match.slice(1).map(Number);
+  const start = startH * 60 + startM;
+  const end = endH * 60 + endM;
+
+  const parts = new Intl.DateTimeFormat('en-US', {
+    timeZone: TIMEZONE,
+    hour: 'numeric',
+    minute: 'numeric',
+    hourCycle: 'h23',
+  }).formatToParts(now);
+  const field = (type: string) =>
+    Number(parts.find((p) => p.type === type)?.value);
+  const current = field('hour') * 60 + field('minute');
+
+  return start <= end
+    ? current >= start && current < end
+    : current >= start || current < end;
+}
+
 async function runTask(
   task: ScheduledTask,
   deps: SchedulerDependencies,
+  manual = false,
 ): Promise<void> {
   const startTime = Date.now();
   const groupDir = path.join(GROUPS_DIR, task.group_folder);
@@ -162,7 +256,10 @@ async function runTask(
   });
 
   let nextRun: string | null = null;
-  if (task.schedule_type === 'cron') {
+  if (manual) {
+    // Manual runs don't advance the schedule — keep whatever next_run is current
+    nextRun = getTaskById(task.id)?.next_run ?? null;
+  } else if (task.schedule_type === 'cron') {
     const interval = CronExpressionParser.parse(task.schedule_value, {
       tz: TIMEZONE,
     });
@@ -181,6 +278,22 @@ async function runTask(
   updateTaskAfterRun(task.id, nextRun, resultSummary);
 }
 
+/**
+ * Run a task immediately, outside its schedule.
+ * The run is logged like any other, but next_run and status are left alone.
+ */
+export function runTaskNow(
+  task: ScheduledTask,
+  deps: SchedulerDependencies,
+): void {
+  logger.info({ taskId: task.id }, 'Manual task run requested');
+  // Separate queue key so a scheduled run already queued for this task
+  // doesn't swallow the manual one
+  deps.queue.enqueueTask(task.chat_jid, `${task.id}:manual`, () =>
+    runTask(task, deps, true),
+  );
+}
+
 let schedulerRunning = false;
 
 export function startSchedulerLoop(deps: SchedulerDependencies): void {
@@ -191,6 +304,13 @@ export function startSchedulerLoop(deps: SchedulerDependencies): void {
   schedulerRunning = true;
   logger.info('Scheduler loop started');
 
+  if (QUIET_HOURS && !isValidQuietHours(QUIET_HOURS)) {
+    logger.warn(
+      { quietHours: QUIET_HOURS },
+      'Invalid QUIET_HOURS (expected "HH:MM-HH:MM"), ignoring',
+    );
+  }
+
   const loop = async () => {
     try {
       const dueTasks = getDueTasks();
@@ -205,6 +325,19 @@ export function startSchedulerLoop(deps: SchedulerDependencies): void {
           continue;
         }
 
+        // Hold tasks during quiet hours. next_run is left in the past, so
+        // the task is picked up on the first poll after the window ends.
+        const group = Object.values(deps.registeredGroups()).find(
+          (g) => g.folder === currentTask.group_folder,
+        );
+        if (isQuietTime(group?.quietHours || QUIET_HOURS)) {
+          logger.debug(
+            { taskId: currentTask.id, group: currentTask.group_folder },
+            'Quiet hours, deferring task',
+          );
+          continue;
+        }
+
         deps.queue.enqueueTask(
           currentTask.chat_jid,
           currentTask.id,
@@ -27,9 +27,22 @@ export interface AllowedRoot {
   description?: string;
 }
 
+/**
+ * Sender Allowlist - Who may trigger the agent in each chat
+ * This file should be stored at ~/.config/nanoclaw/sender-allowlist.json
+ * and is NOT mounted into any container, making it tamper-proof from agents.
+ */
+export interface SenderAllowlist {
+  // Chat JID -> sender JIDs allowed to trigger the agent there
+  chats: Record<string, string[]>;
+  // Senders allowed in chats not listed above. Omit to allow anyone.
+  default?: string[];
+}
+
 export interface ContainerConfig {
   additionalMounts?: AdditionalMount[];
   timeout?: number; // Default: 300000 (5 minutes)
+  readOnlyRootfs?: boolean; // Default: false. Only mounts, /tmp and $HOME stay writable
 }
 
 export interface RegisteredGroup {
@@ -39,6 +52,7 @@ export interface RegisteredGroup {
   added_at: string;
   containerConfig?: ContainerConfig;
   requiresTrigger?: boolean; // Default: true for groups, false for solo chats
+  quietHours?: string; // "HH:MM-HH:MM" local time, or "off". Overrides QUIET_HOURS
 }
 
 export interface NewMessage {
@@ -2,6 +2,10 @@ import { defineConfig } from 'vitest/config';
 
 export default defineConfig({
   test: {
-    include: ['src/**/*.test.ts', 'skills-engine/**/*.test.ts'],
+    include: [
+      'src/**/*.test.ts',
+      'skills-engine/**/*.test.ts',
+      'container/agent-runner/src/**/*.test.ts',
+    ],
   },
 });